@@ -3,39 +3,477 @@
 
 use std::{
     fs::File,
-    io::{self, BufWriter, Seek, Write},
+    io::{self, BufReader, BufWriter, Read, Seek, Write},
     path::PathBuf,
+    sync::Mutex,
 };
 
+#[cfg(unix)]
+use std::os::unix::fs::FileExt;
+#[cfg(windows)]
+use std::os::windows::fs::FileExt;
+
+#[cfg(feature = "checksum")]
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "checksum")]
+use serde::Serialize;
+
+#[cfg(feature = "checksum")]
+struct ChecksumState {
+    whole: Sha256,
+    parts: Vec<Sha256>,
+    file_names: Vec<String>,
+}
+
+/// A part's entry in a [`SplitManifest`]: its file name, byte size, and SHA-256 digest.
+#[cfg(feature = "checksum")]
+#[derive(Serialize)]
+pub struct PartManifest {
+    pub file_name: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// Describes a completed split job: total length, split size, each part's manifest, and a
+/// whole-file SHA-256. Written as `manifest.json` into `dest_dir` by [`SplitWriter::finish`].
+/// [`SplitReader`] does not read or verify it.
+#[cfg(feature = "checksum")]
+#[derive(Serialize)]
+pub struct SplitManifest {
+    pub total_len: u64,
+    pub split_size: u64,
+    pub parts: Vec<PartManifest>,
+    pub sha256: String,
+}
+
+/// Derives the `split_size` for [`SplitWriter::with_part_count`] (`ceil(total_len / n_parts)`),
+/// validating that both arguments are greater than zero.
+pub fn part_count_to_split_size(total_len: u64, n_parts: u64) -> io::Result<u64> {
+    if total_len == 0 || n_parts == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "total_len and n_parts must both be greater than zero",
+        ));
+    }
+
+    Ok(total_len.div_ceil(n_parts))
+}
+
+enum Namer {
+    Closure(Box<dyn Fn(usize) -> String + Send + Sync>),
+    Suffix(SuffixStyle),
+}
+
+impl Namer {
+    fn file_name(&self, n: usize) -> io::Result<String> {
+        match self {
+            Namer::Closure(f) => Ok(f(n)),
+            Namer::Suffix(style) => style.generate(n),
+        }
+    }
+}
+
+/// A naming policy for the part files produced by a [`SplitWriter`].
+///
+/// Build one with [`SplitWriter::builder`] and [`SplitWriterBuilder::suffix`] instead of
+/// hand-writing a `get_file_name` closure.
+pub enum SuffixStyle {
+    /// `file.001`, `file.002`, ... A fixed `width` of digits starting at `start`. When `pad` is
+    /// `false` the value is not zero-padded and is not bounded by `width` at all — it can grow
+    /// past `width` digits without error.
+    Numeric {
+        file_stem: String,
+        width: usize,
+        start: usize,
+        pad: bool,
+    },
+    /// `fileaa`, `fileab`, ..., `fileaz`, `fileba`, ... The coreutils `split` default: a fixed
+    /// `length` of lowercase letters that rolls over like a base-26 counter.
+    Alphabetic {
+        file_stem: String,
+        length: usize,
+        start: usize,
+    },
+    /// `file.part0.ext`, `file.part1.ext`, ... The convention this crate already used.
+    PartExt { file_stem: String, file_ext: String },
+    /// `name.wbf1`, `name.wbf2`, ... A plain, unpadded digit appended directly to `base_name`.
+    TrailingDigit { base_name: String, start: usize },
+}
+
+impl SuffixStyle {
+    fn generate(&self, n: usize) -> io::Result<String> {
+        match self {
+            SuffixStyle::Numeric {
+                file_stem,
+                width,
+                start,
+                pad,
+            } => {
+                let value = start + n;
+
+                if *pad {
+                    let cap = 10u64.checked_pow(u32::try_from(*width).unwrap_or(u32::MAX));
+                    if !matches!(cap, Some(cap) if (value as u64) < cap) {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!(
+                                "part index {value} does not fit in a {width}-digit numeric suffix"
+                            ),
+                        ));
+                    }
+
+                    Ok(format!("{file_stem}.{value:0width$}"))
+                } else {
+                    Ok(format!("{file_stem}.{value}"))
+                }
+            }
+            SuffixStyle::Alphabetic {
+                file_stem,
+                length,
+                start,
+            } => {
+                let value = start + n;
+                let cap = 26u64.checked_pow(u32::try_from(*length).unwrap_or(u32::MAX));
+                if !matches!(cap, Some(cap) if (value as u64) < cap) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("part index {value} does not fit in a {length}-letter alphabetic suffix"),
+                    ));
+                }
+
+                let mut remaining = value as u64;
+                let mut letters = vec![b'a'; *length];
+                for slot in letters.iter_mut().rev() {
+                    *slot = b'a' + (remaining % 26) as u8;
+                    remaining /= 26;
+                }
+
+                let suffix = String::from_utf8(letters).expect("suffix is ASCII");
+                Ok(format!("{file_stem}{suffix}"))
+            }
+            SuffixStyle::PartExt {
+                file_stem,
+                file_ext,
+            } => Ok(format!("{file_stem}.part{n}.{file_ext}")),
+            SuffixStyle::TrailingDigit { base_name, start } => {
+                Ok(format!("{base_name}{}", start + n))
+            }
+        }
+    }
+}
+
+pub struct SplitWriterBuilder {
+    dest_dir: PathBuf,
+    split_size: u64,
+    suffix: Option<SuffixStyle>,
+    #[cfg(feature = "checksum")]
+    checksum: bool,
+}
+
+impl SplitWriterBuilder {
+    pub fn suffix(mut self, style: SuffixStyle) -> SplitWriterBuilder {
+        self.suffix = Some(style);
+        self
+    }
+
+    /// Enables an incremental SHA-256 digest of the whole file and of each part, written out as
+    /// a `manifest.json` in `dest_dir` by [`SplitWriter::finish`].
+    #[cfg(feature = "checksum")]
+    pub fn checksum(mut self) -> SplitWriterBuilder {
+        self.checksum = true;
+        self
+    }
+
+    pub fn build(self) -> io::Result<SplitWriter> {
+        let Some(suffix) = self.suffix else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing suffix style",
+            ));
+        };
+
+        #[cfg_attr(not(feature = "checksum"), allow(unused_mut))]
+        let mut writer =
+            SplitWriter::from_namer(self.dest_dir, Namer::Suffix(suffix), self.split_size)?;
+
+        #[cfg(feature = "checksum")]
+        if self.checksum {
+            writer.enable_checksum()?;
+        }
+
+        Ok(writer)
+    }
+}
+
+#[cfg(unix)]
+fn write_all_at(file: &File, mut buf: &[u8], mut offset: u64) -> io::Result<()> {
+    while !buf.is_empty() {
+        let n = file.write_at(buf, offset)?;
+        if n == 0 {
+            return Err(io::Error::from(io::ErrorKind::WriteZero));
+        }
+        buf = &buf[n..];
+        offset += n as u64;
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn write_all_at(file: &File, mut buf: &[u8], mut offset: u64) -> io::Result<()> {
+    while !buf.is_empty() {
+        let n = file.seek_write(buf, offset)?;
+        if n == 0 {
+            return Err(io::Error::from(io::ErrorKind::WriteZero));
+        }
+        buf = &buf[n..];
+        offset += n as u64;
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "parallelism", unix))]
+fn read_all_at(file: &File, mut buf: &mut [u8], mut offset: u64) -> io::Result<()> {
+    while !buf.is_empty() {
+        let n = file.read_at(buf, offset)?;
+        if n == 0 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+        buf = &mut buf[n..];
+        offset += n as u64;
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "parallelism", windows))]
+fn read_all_at(file: &File, mut buf: &mut [u8], mut offset: u64) -> io::Result<()> {
+    while !buf.is_empty() {
+        let n = file.seek_read(buf, offset)?;
+        if n == 0 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+        buf = &mut buf[n..];
+        offset += n as u64;
+    }
+    Ok(())
+}
+
 pub struct SplitWriter {
     split_size: u64,
     dest_dir: PathBuf,
-    get_file_name: Box<dyn Fn(usize) -> String>,
+    namer: Namer,
     current_pos: u64,
     writers: Vec<BufWriter<File>>,
+    positioned_files: Mutex<Vec<Option<File>>>,
+    #[cfg(feature = "checksum")]
+    checksum: Option<ChecksumState>,
 }
 
 impl SplitWriter {
     pub fn new(
         dest_dir: PathBuf,
-        get_file_name: impl Fn(usize) -> String + 'static,
+        get_file_name: impl Fn(usize) -> String + Send + Sync + 'static,
         split_size: u64,
     ) -> io::Result<SplitWriter> {
-        let first_file_path = dest_dir.join(get_file_name(0));
+        Self::from_namer(
+            dest_dir,
+            Namer::Closure(Box::new(get_file_name)),
+            split_size,
+        )
+    }
+
+    pub fn builder(dest_dir: PathBuf, split_size: u64) -> SplitWriterBuilder {
+        SplitWriterBuilder {
+            dest_dir,
+            split_size,
+            suffix: None,
+            #[cfg(feature = "checksum")]
+            checksum: false,
+        }
+    }
+
+    /// Builds a [`SplitWriter`] sized to produce around `n_parts` files instead of a writer sized
+    /// by a fixed byte boundary, deriving `split_size = ceil(total_len / n_parts)` the way
+    /// coreutils `split -n` does (the last part absorbs the remainder). Because every part but
+    /// the last is the same `split_size`, this can produce *fewer* than `n_parts` parts when
+    /// `total_len` doesn't divide evenly against that size (e.g. `total_len=4, n_parts=3` yields
+    /// `split_size=2` and only 2 parts) — it never produces more.
+    pub fn with_part_count(
+        dest_dir: PathBuf,
+        get_file_name: impl Fn(usize) -> String + Send + Sync + 'static,
+        total_len: u64,
+        n_parts: u64,
+    ) -> io::Result<SplitWriter> {
+        let split_size = part_count_to_split_size(total_len, n_parts)?;
+
+        SplitWriter::new(dest_dir, get_file_name, split_size)
+    }
+
+    fn from_namer(dest_dir: PathBuf, namer: Namer, split_size: u64) -> io::Result<SplitWriter> {
+        let first_file_path = dest_dir.join(namer.file_name(0)?);
         let first_writer = BufWriter::new(File::create(first_file_path)?);
         let writers = vec![first_writer];
 
         let split_writer = SplitWriter {
             split_size,
             dest_dir,
-            get_file_name: Box::new(get_file_name),
+            namer,
             current_pos: 0,
             writers,
+            positioned_files: Mutex::new(Vec::new()),
+            #[cfg(feature = "checksum")]
+            checksum: None,
         };
 
         Ok(split_writer)
     }
 
+    /// Writes `buf` at the logical `offset`, mapping it onto the owning part file(s) and writing
+    /// through a positioned write (`pwrite`/`seek_write`) instead of the shared sequential
+    /// cursor used by [`Write`]. Missing part files are created on demand. Splits `buf` at part
+    /// boundaries so a single call may span more than one part.
+    ///
+    /// Because this takes `&self`, it can be called concurrently from multiple threads as long
+    /// as they target disjoint byte ranges — see [`SplitWriter::par_copy_from`].
+    ///
+    /// Positioned writes bypass the incremental checksum hasher and the sequential
+    /// [`Write`]/[`Seek`] bookkeeping, so this returns an error if
+    /// [`SplitWriterBuilder::checksum`] was enabled on this writer.
+    pub fn write_at(&self, offset: u64, buf: &[u8]) -> io::Result<()> {
+        #[cfg(feature = "checksum")]
+        if self.checksum.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "write_at cannot be used on a SplitWriter with checksum enabled",
+            ));
+        }
+
+        let mut offset = offset;
+        let mut remaining = buf;
+
+        while !remaining.is_empty() {
+            #[allow(clippy::cast_possible_truncation)]
+            let i = (offset / self.split_size) as usize;
+            let offset_in_part = offset % self.split_size;
+            let remaining_in_part = self.split_size - offset_in_part;
+
+            #[allow(clippy::cast_possible_truncation)]
+            let n = remaining.len().min(remaining_in_part as usize);
+            let (chunk, rest) = remaining.split_at(n);
+
+            self.write_part_at(i, offset_in_part, chunk)?;
+
+            remaining = rest;
+            offset += n as u64;
+        }
+
+        Ok(())
+    }
+
+    fn write_part_at(&self, i: usize, offset_in_part: u64, buf: &[u8]) -> io::Result<()> {
+        // Only the bookkeeping (resizing the table, opening the file once) happens under the
+        // lock; the handed-out clone is written to lock-free so concurrent writers to disjoint
+        // parts (or disjoint offsets of the same part) don't serialize on this mutex.
+        let file = {
+            let mut files = self.positioned_files.lock().unwrap();
+
+            if files.len() <= i {
+                files.resize_with(i + 1, || None);
+            }
+
+            if files[i].is_none() {
+                let file_name = self.namer.file_name(i)?;
+                let file = File::options()
+                    .write(true)
+                    .create(true)
+                    .truncate(false)
+                    .open(self.dest_dir.join(file_name))?;
+                files[i] = Some(file);
+            }
+
+            files[i].as_ref().unwrap().try_clone()?
+        };
+
+        write_all_at(&file, buf, offset_in_part)
+    }
+
+    /// Fills every part by reading `source` in `block_size`-sized chunks and dispatching each
+    /// block to [`SplitWriter::write_at`] on a rayon thread pool, so worker threads each own a
+    /// disjoint byte range instead of sharing a cursor. `source` must support positioned reads
+    /// (`pread`/`seek_read`).
+    #[cfg(feature = "parallelism")]
+    pub fn par_copy_from(&self, source: &File, total_len: u64, block_size: u64) -> io::Result<()> {
+        use rayon::prelude::*;
+
+        assert!(block_size > 0, "block_size must be greater than zero");
+
+        let n_blocks = total_len.div_ceil(block_size);
+
+        (0..n_blocks).into_par_iter().try_for_each(|block_i| {
+            let offset = block_i * block_size;
+            let len = block_size.min(total_len - offset);
+
+            #[allow(clippy::cast_possible_truncation)]
+            let mut buf = vec![0u8; len as usize];
+            read_all_at(source, &mut buf, offset)?;
+
+            self.write_at(offset, &buf)
+        })
+    }
+
+    #[cfg(feature = "checksum")]
+    fn enable_checksum(&mut self) -> io::Result<()> {
+        let first_file_name = self.namer.file_name(0)?;
+
+        self.checksum = Some(ChecksumState {
+            whole: Sha256::new(),
+            parts: vec![Sha256::new()],
+            file_names: vec![first_file_name],
+        });
+
+        Ok(())
+    }
+
+    /// Flushes every part, finalizes the checksums enabled via
+    /// [`SplitWriterBuilder::checksum`], and writes a `manifest.json` describing the job (total
+    /// length, split size, and each part's name/size/SHA-256) into `dest_dir`.
+    #[cfg(feature = "checksum")]
+    pub fn finish(mut self) -> io::Result<SplitManifest> {
+        let Some(state) = self.checksum.take() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "checksum was not enabled on this SplitWriter",
+            ));
+        };
+
+        let total_len = self.total_len()?;
+
+        let mut parts = Vec::with_capacity(self.writers.len());
+        for (i, writer) in self.writers.iter_mut().enumerate() {
+            writer.flush()?;
+            let size = writer.get_ref().metadata()?.len();
+            let sha256 = format!("{:x}", state.parts[i].clone().finalize());
+
+            parts.push(PartManifest {
+                file_name: state.file_names[i].clone(),
+                size,
+                sha256,
+            });
+        }
+
+        let manifest = SplitManifest {
+            total_len,
+            split_size: self.split_size,
+            parts,
+            sha256: format!("{:x}", state.whole.clone().finalize()),
+        };
+
+        let manifest_file = File::create(self.dest_dir.join("manifest.json"))?;
+        serde_json::to_writer_pretty(manifest_file, &manifest).map_err(io::Error::other)?;
+
+        Ok(manifest)
+    }
+
     pub fn total_len(&mut self) -> io::Result<u64> {
         if self.writers.is_empty() {
             return Ok(0);
@@ -59,10 +497,16 @@ impl Write for SplitWriter {
         let i = (self.current_pos / self.split_size) as usize;
 
         if i >= self.writers.len() {
-            let file_name = (self.get_file_name)(i);
-            let file_path = self.dest_dir.join(file_name);
+            let file_name = self.namer.file_name(i)?;
+            let file_path = self.dest_dir.join(&file_name);
             let writer = BufWriter::new(File::create(file_path)?);
             self.writers.push(writer);
+
+            #[cfg(feature = "checksum")]
+            if let Some(state) = &mut self.checksum {
+                state.parts.push(Sha256::new());
+                state.file_names.push(file_name);
+            }
         }
 
         let writer = &mut self.writers[i];
@@ -72,6 +516,12 @@ impl Write for SplitWriter {
         let n_written = writer.write(&buf[..n_to_write])?;
         self.current_pos += n_written as u64;
 
+        #[cfg(feature = "checksum")]
+        if let Some(state) = &mut self.checksum {
+            state.whole.update(&buf[..n_written]);
+            state.parts[i].update(&buf[..n_written]);
+        }
+
         Ok(n_written)
     }
 
@@ -124,3 +574,308 @@ impl Seek for SplitWriter {
         Ok(self.current_pos)
     }
 }
+
+struct Part {
+    path: PathBuf,
+    begin: u64,
+    size: u64,
+}
+
+pub struct SplitReader {
+    parts: Vec<Part>,
+    total_len: u64,
+    pos: u64,
+    current: Option<(usize, BufReader<File>)>,
+}
+
+impl SplitReader {
+    /// `split_size` is validated against every part but the last (which may be shorter): a
+    /// mismatch means the files on disk weren't produced by a [`SplitWriter`] with this
+    /// `split_size`, and stitching them together would desync `seek`/`read`.
+    pub fn new(
+        dest_dir: PathBuf,
+        get_file_name: impl Fn(usize) -> String + Send + Sync,
+        split_size: u64,
+    ) -> io::Result<SplitReader> {
+        let mut paths = Vec::new();
+        let mut i = 0;
+
+        loop {
+            let path = dest_dir.join(get_file_name(i));
+            if !path.is_file() {
+                break;
+            }
+            paths.push(path);
+            i += 1;
+        }
+
+        let reader = Self::from_paths(paths)?;
+
+        let last_i = reader.parts.len() - 1;
+        for (i, part) in reader.parts.iter().enumerate() {
+            if i != last_i && part.size != split_size {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "part {i} has size {} but expected split_size {split_size}",
+                        part.size
+                    ),
+                ));
+            }
+        }
+
+        Ok(reader)
+    }
+
+    pub fn from_paths(paths: Vec<PathBuf>) -> io::Result<SplitReader> {
+        if paths.is_empty() {
+            return Err(io::Error::from(io::ErrorKind::NotFound));
+        }
+
+        let mut parts = Vec::with_capacity(paths.len());
+        let mut begin = 0;
+
+        for path in paths {
+            let size = File::open(&path)?.metadata()?.len();
+            parts.push(Part { path, begin, size });
+            begin += size;
+        }
+
+        Ok(SplitReader {
+            parts,
+            total_len: begin,
+            pos: 0,
+            current: None,
+        })
+    }
+
+    pub fn total_len(&self) -> u64 {
+        self.total_len
+    }
+
+    fn locate(&self, pos: u64) -> io::Result<(usize, u64)> {
+        for (i, part) in self.parts.iter().enumerate() {
+            if pos < part.begin + part.size {
+                return Ok((i, pos - part.begin));
+            }
+        }
+
+        if pos == self.total_len {
+            let last_i = self.parts.len() - 1;
+            let last = &self.parts[last_i];
+            return Ok((last_i, last.size));
+        }
+
+        Err(io::Error::from(io::ErrorKind::UnexpectedEof))
+    }
+
+    fn use_part(&mut self, i: usize) -> io::Result<&mut BufReader<File>> {
+        if !matches!(&self.current, Some((cur_i, _)) if *cur_i == i) {
+            let file = File::open(&self.parts[i].path)?;
+            self.current = Some((i, BufReader::new(file)));
+        }
+
+        Ok(&mut self.current.as_mut().unwrap().1)
+    }
+}
+
+impl Read for SplitReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos >= self.total_len {
+            return Ok(0);
+        }
+
+        let (i, offset_in_part) = self.locate(self.pos)?;
+        let remaining_in_part = self.parts[i].size - offset_in_part;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let n_to_read = buf.len().min(remaining_in_part as usize);
+
+        let reader = self.use_part(i)?;
+        let n_read = reader.read(&mut buf[..n_to_read])?;
+        self.pos += n_read as u64;
+
+        Ok(n_read)
+    }
+}
+
+impl Seek for SplitReader {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let new_pos = match pos {
+            io::SeekFrom::Start(n) => n,
+            io::SeekFrom::End(n) => self
+                .total_len
+                .checked_add_signed(n)
+                .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?,
+            io::SeekFrom::Current(n) => self
+                .pos
+                .checked_add_signed(n)
+                .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?,
+        };
+
+        let (i, offset_in_part) = self.locate(new_pos)?;
+        let reader = self.use_part(i)?;
+        reader.seek(io::SeekFrom::Start(offset_in_part))?;
+        self.pos = new_pos;
+
+        Ok(self.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "split-write-test-{label}-{:?}",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn round_trips_through_writer_and_reader() {
+        let dir = temp_dir("round-trip");
+        let data: Vec<u8> = (0..250u32).map(|n| (n % 256) as u8).collect();
+
+        let mut writer = SplitWriter::builder(dir.clone(), 32)
+            .suffix(SuffixStyle::PartExt {
+                file_stem: "file".to_owned(),
+                file_ext: "bin".to_owned(),
+            })
+            .build()
+            .unwrap();
+        writer.write_all(&data).unwrap();
+        drop(writer);
+
+        let mut reader = SplitReader::new(dir, |n| format!("file.part{n}.bin"), 32).unwrap();
+        let mut read_back = Vec::new();
+        reader.read_to_end(&mut read_back).unwrap();
+
+        assert_eq!(read_back, data);
+    }
+
+    #[test]
+    fn with_part_count_rejects_zero_n_parts() {
+        let dir = temp_dir("zero-n-parts");
+        let result = SplitWriter::with_part_count(dir, |n| format!("file.part{n}"), 100, 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn with_part_count_can_produce_fewer_than_n_parts() {
+        let dir = temp_dir("fewer-parts");
+        let mut writer =
+            SplitWriter::with_part_count(dir.clone(), |n| format!("file.part{n}"), 4, 3).unwrap();
+        writer.write_all(&[0u8; 4]).unwrap();
+        drop(writer);
+
+        let part_count = (0..)
+            .take_while(|n| dir.join(format!("file.part{n}")).is_file())
+            .count();
+        assert_eq!(part_count, 2);
+    }
+
+    #[test]
+    fn numeric_suffix_rejects_overflow_when_padded() {
+        let style = SuffixStyle::Numeric {
+            file_stem: "file".to_owned(),
+            width: 1,
+            start: 0,
+            pad: true,
+        };
+        assert!(style.generate(9).is_ok());
+        assert!(style.generate(10).is_err());
+    }
+
+    #[test]
+    fn alphabetic_suffix_rolls_over_like_base_26() {
+        let style = SuffixStyle::Alphabetic {
+            file_stem: "file".to_owned(),
+            length: 2,
+            start: 0,
+        };
+        assert_eq!(style.generate(0).unwrap(), "fileaa");
+        assert_eq!(style.generate(25).unwrap(), "fileaz");
+        assert_eq!(style.generate(26).unwrap(), "fileba");
+        assert!(style.generate(26 * 26).is_err());
+    }
+
+    #[test]
+    fn trailing_digit_suffix_appends_unpadded_counter() {
+        let style = SuffixStyle::TrailingDigit {
+            base_name: "name.wbf".to_owned(),
+            start: 1,
+        };
+        assert_eq!(style.generate(0).unwrap(), "name.wbf1");
+        assert_eq!(style.generate(9).unwrap(), "name.wbf10");
+    }
+
+    #[cfg(feature = "checksum")]
+    #[test]
+    fn checksum_manifest_matches_data_and_round_trips() {
+        let dir = temp_dir("checksum-manifest");
+        let data: Vec<u8> = (0..100u32).map(|n| (n % 256) as u8).collect();
+
+        let mut writer = SplitWriter::builder(dir.clone(), 32)
+            .suffix(SuffixStyle::PartExt {
+                file_stem: "file".to_owned(),
+                file_ext: "bin".to_owned(),
+            })
+            .checksum()
+            .build()
+            .unwrap();
+        writer.write_all(&data).unwrap();
+        let manifest = writer.finish().unwrap();
+
+        assert_eq!(manifest.total_len, data.len() as u64);
+        assert_eq!(manifest.parts.len(), 4);
+        assert_eq!(manifest.sha256, format!("{:x}", Sha256::digest(&data)));
+
+        let mut reader = SplitReader::new(dir, |n| format!("file.part{n}.bin"), 32).unwrap();
+        let mut read_back = Vec::new();
+        reader.read_to_end(&mut read_back).unwrap();
+        assert_eq!(read_back, data);
+    }
+
+    #[test]
+    fn write_at_spans_multiple_parts() {
+        let dir = temp_dir("write-at-span");
+        let writer = SplitWriter::new(dir.clone(), |n| format!("file.part{n}"), 8).unwrap();
+
+        writer.write_at(4, &[1u8; 8]).unwrap();
+        drop(writer);
+
+        let mut reader = SplitReader::new(dir, |n| format!("file.part{n}"), 8).unwrap();
+        let mut read_back = Vec::new();
+        reader.read_to_end(&mut read_back).unwrap();
+
+        assert_eq!(read_back, vec![0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1]);
+    }
+
+    #[cfg(feature = "parallelism")]
+    #[test]
+    fn par_copy_from_fills_every_part() {
+        let dir = temp_dir("par-copy-from");
+        let data: Vec<u8> = (0..200u32).map(|n| (n % 256) as u8).collect();
+
+        let source_path = dir.join("source.bin");
+        std::fs::write(&source_path, &data).unwrap();
+        let source_file = File::open(&source_path).unwrap();
+
+        let writer = SplitWriter::new(dir.clone(), |n| format!("file.part{n}"), 32).unwrap();
+        writer
+            .par_copy_from(&source_file, data.len() as u64, 16)
+            .unwrap();
+        drop(writer);
+
+        let mut reader = SplitReader::new(dir, |n| format!("file.part{n}"), 32).unwrap();
+        let mut read_back = Vec::new();
+        reader.read_to_end(&mut read_back).unwrap();
+
+        assert_eq!(read_back, data);
+    }
+}
@@ -8,13 +8,23 @@ use std::path::PathBuf;
 use size::Size;
 
 #[cfg(feature = "cli")]
-const USAGE: &str = "Usage: split-write [-s|--split-size SIZE] SOURCE DESTDIR";
+const USAGE: &str = "Usage: split-write [-s|--split-size SIZE | -n|--number N] [--numeric-suffixes] [--suffix-length N] [--checksum] [--parallel] SOURCE DESTDIR";
+
+#[cfg(all(feature = "cli", feature = "parallelism"))]
+const PAR_BLOCK_SIZE: u64 = 4 * 1024 * 1024;
 
 #[cfg(feature = "cli")]
 struct Args {
     split_size: Size,
+    n_parts: Option<u64>,
     source: PathBuf,
     dest_dir: PathBuf,
+    numeric_suffixes: bool,
+    suffix_length: usize,
+    #[cfg(feature = "checksum")]
+    checksum: bool,
+    #[cfg(feature = "parallelism")]
+    parallel: bool,
 }
 
 #[cfg(feature = "cli")]
@@ -22,8 +32,15 @@ fn parse_args() -> Result<Args, lexopt::Error> {
     use lexopt::prelude::*;
 
     let mut split_size = Size::from_bytes(0);
+    let mut n_parts = None;
     let mut source = PathBuf::new();
     let mut dest_dir = PathBuf::new();
+    let mut numeric_suffixes = false;
+    let mut suffix_length = 2;
+    #[cfg(feature = "checksum")]
+    let mut checksum = false;
+    #[cfg(feature = "parallelism")]
+    let mut parallel = false;
 
     let mut parser = lexopt::Parser::from_env();
     while let Some(arg) = parser.next()? {
@@ -39,6 +56,25 @@ fn parse_args() -> Result<Args, lexopt::Error> {
                 let size_str = parser.value()?.to_string_lossy().to_string();
                 split_size = Size::from_str(size_str.as_str()).expect("Failed to parse split size");
             }
+            Short('n') | Long("number") => {
+                let n_str = parser.value()?.to_string_lossy().to_string();
+                n_parts = Some(n_str.parse().expect("Failed to parse number of parts"));
+            }
+            Long("numeric-suffixes") => {
+                numeric_suffixes = true;
+            }
+            Long("suffix-length") => {
+                let len_str = parser.value()?.to_string_lossy().to_string();
+                suffix_length = len_str.parse().expect("Failed to parse suffix length");
+            }
+            #[cfg(feature = "checksum")]
+            Long("checksum") => {
+                checksum = true;
+            }
+            #[cfg(feature = "parallelism")]
+            Long("parallel") => {
+                parallel = true;
+            }
             Short('h') | Long("help") => {
                 eprintln!("{USAGE}");
                 std::process::exit(0);
@@ -51,14 +87,21 @@ fn parse_args() -> Result<Args, lexopt::Error> {
 
     Ok(Args {
         split_size,
+        n_parts,
         source,
         dest_dir,
+        numeric_suffixes,
+        suffix_length,
+        #[cfg(feature = "checksum")]
+        checksum,
+        #[cfg(feature = "parallelism")]
+        parallel,
     })
 }
 
 #[cfg(feature = "cli")]
 fn main() -> Result<(), lexopt::Error> {
-    use split_write::SplitWriter;
+    use split_write::{part_count_to_split_size, SplitWriter, SuffixStyle};
     use std::{ffi::OsStr, fs::File, io::BufReader};
 
     let args = parse_args()?;
@@ -84,19 +127,63 @@ fn main() -> Result<(), lexopt::Error> {
         panic!("Source path must have an extension");
     };
 
-    let Ok(split_size) = args.split_size.bytes().try_into() else {
-        panic!("Invalid split size");
+    let source_file = File::open(&args.source).expect("Failed to open source file");
+    let total_len = source_file
+        .metadata()
+        .expect("Failed to stat source file")
+        .len();
+
+    let split_size = if let Some(n_parts) = args.n_parts {
+        part_count_to_split_size(total_len, n_parts).expect("Invalid number of parts")
+    } else {
+        let Ok(split_size) = args.split_size.bytes().try_into() else {
+            panic!("Invalid split size");
+        };
+        split_size
     };
 
-    let get_file_name = move |n| format!("{file_stem}.part{n}.{file_ext}");
+    let mut builder = SplitWriter::builder(args.dest_dir, split_size);
+    builder = if args.numeric_suffixes {
+        builder.suffix(SuffixStyle::Numeric {
+            file_stem,
+            width: args.suffix_length,
+            start: 0,
+            pad: true,
+        })
+    } else {
+        builder.suffix(SuffixStyle::PartExt {
+            file_stem,
+            file_ext,
+        })
+    };
 
-    let source_file = File::open(&args.source).expect("Failed to open source file");
-    let mut reader = BufReader::new(source_file);
+    #[cfg(feature = "checksum")]
+    if args.checksum {
+        builder = builder.checksum();
+    }
 
-    let mut writer = SplitWriter::new(args.dest_dir, get_file_name, split_size)
-        .expect("Failed to create split writer");
+    let mut writer = builder.build().expect("Failed to create split writer");
+
+    #[cfg(feature = "parallelism")]
+    if args.parallel {
+        writer
+            .par_copy_from(&source_file, total_len, PAR_BLOCK_SIZE)
+            .expect("Failed to copy file");
+    } else {
+        let mut reader = BufReader::new(source_file);
+        std::io::copy(&mut reader, &mut writer).expect("Failed to copy file");
+    }
 
-    std::io::copy(&mut reader, &mut writer).expect("Failed to copy file");
+    #[cfg(not(feature = "parallelism"))]
+    {
+        let mut reader = BufReader::new(source_file);
+        std::io::copy(&mut reader, &mut writer).expect("Failed to copy file");
+    }
+
+    #[cfg(feature = "checksum")]
+    if args.checksum {
+        writer.finish().expect("Failed to write checksum manifest");
+    }
 
     Ok(())
 }